@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+///A normalized frequency distribution over the 26 lowercase letters (each value in
+///`0.0..=1.0`, summing to 1.0) used to score how closely a piece of text matches a
+///particular language. Ciphertext-only attacks compare a candidate plaintext's own
+///letter distribution against one of these to judge how "English" (or French, German,
+///etc.) it looks.
+#[derive(Debug, Clone)]
+pub struct FreqTable {
+    frequencies: HashMap<char, f32>,
+}
+
+impl FreqTable {
+    ///Builds a table from raw letter counts (e.g. tallied from a representative corpus),
+    ///normalizing them so the frequencies sum to 1.0.
+    pub fn from_counts<I: IntoIterator<Item = (char, u32)>>(counts: I) -> Self {
+        let counts = counts.into_iter()
+            .map(|(ch, count)| (ch.to_ascii_lowercase(), count))
+            .collect::<HashMap<char, u32>>();
+
+        let total: u32 = counts.values().sum();
+
+        let frequencies = counts.into_iter()
+            .map(|(ch, count)| (ch, count as f32 / total as f32))
+            .collect();
+
+        FreqTable { frequencies }
+    }
+
+    ///Loads a table from a simple "letter,frequency" text source, one pair per line
+    ///(e.g. `a,0.080`), normalizing the parsed frequencies so they sum to 1.0. Blank
+    ///lines are ignored.
+    pub fn from_source<S: AsRef<str>>(source: S) -> Result<Self, String> {
+        let mut frequencies = HashMap::new();
+
+        for line in source.as_ref().lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (letter, frequency) = line.split_once(',').ok_or_else(|| format!("malformed line: {line}"))?;
+            let ch = letter.trim().chars().next().ok_or_else(|| format!("malformed line: {line}"))?;
+            let frequency = frequency.trim().parse::<f32>().map_err(|_| format!("malformed line: {line}"))?;
+
+            frequencies.insert(ch.to_ascii_lowercase(), frequency);
+        }
+
+        let total: f32 = frequencies.values().sum();
+        for frequency in frequencies.values_mut() {
+            *frequency /= total;
+        }
+
+        Ok(FreqTable { frequencies })
+    }
+
+    ///The built-in English letter frequency model, based on D. Denning, S. Akl,
+    /// M. Heckman, T. Lunt, M. Morgenstern, P. Neumann, and R. Schell, “Views for
+    /// Multilevel Database Security,” IEEE Transactions on Software Engineering 13 (2),
+    /// pp. 129–140 (Feb. 1987).
+    pub fn english() -> Self {
+        FreqTable {
+            frequencies: HashMap::from([
+                ('a',0.080),
+                ('b',0.015),
+                ('c',0.030),
+                ('d',0.040),
+                ('e',0.130),
+                ('f',0.020),
+                ('g',0.015),
+                ('h',0.060),
+                ('i',0.065),
+                ('j',0.005),
+                ('k',0.005),
+                ('l',0.035),
+                ('m',0.030),
+                ('n',0.070),
+                ('o',0.080),
+                ('p',0.020),
+                ('q',0.002),
+                ('r',0.065),
+                ('s',0.060),
+                ('t',0.090),
+                ('u',0.030),
+                ('v',0.010),
+                ('w',0.015),
+                ('x',0.005),
+                ('y',0.020),
+                ('z',0.002),
+            ]),
+        }
+    }
+
+    ///Returns the frequency of `c` in this table, or `0.0` if `c` is absent.
+    pub fn get(&self, c: char) -> f32 {
+        *self.frequencies.get(&c.to_ascii_lowercase()).unwrap_or(&0.0)
+    }
+}
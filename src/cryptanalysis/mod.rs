@@ -0,0 +1,4 @@
+pub mod freq_table;
+pub mod shift;
+pub mod vigenere;
+pub mod xor;
@@ -1,55 +1,20 @@
 use std::collections::HashMap;
 use std::iter::zip;
+use crate::cryptanalysis::freq_table::FreqTable;
 use crate::cryptography::caesar::{self, ALPHABET};
 
 /*
     SECTION 1 of 4: CIPHERTEXT-ONLY ATTACK
 */
-lazy_static! {
-    ///A mapping of the percent frequency of each letter in the English language,
-    /// bast on D. Denning, S. Akl, M. Heckman, T. Lunt, M. Morgenstern, P. Neumann, 
-    /// and R. Schell, “Views for Multilevel Database Security,” IEEE Transactions 
-    /// on Software Engineering 13 (2), pp. 129–140 (Feb. 1987).
-    static ref ENGLISH_MODEL: HashMap<char,f32> = HashMap::from([
-        ('a',0.080),
-        ('b',0.015),
-        ('c',0.030),
-        ('d',0.040),
-        ('e',0.130),
-        ('f',0.020),
-        ('g',0.015),
-        ('h',0.060),
-        ('i',0.065),
-        ('j',0.005),
-        ('k',0.005),
-        ('l',0.035),
-        ('m',0.030),
-        ('n',0.070),
-        ('o',0.080),
-        ('p',0.020),
-        ('q',0.002),
-        ('r',0.065),
-        ('s',0.060),
-        ('t',0.090),
-        ('u',0.030),
-        ('v',0.010),
-        ('w',0.015),
-        ('x',0.005),
-        ('y',0.020),
-        ('z',0.002),
-    ]);
-}
 
 ///Performs a ciphertext-only attack on a Caesarian cipher using letter frequency analysis,
-///returning the a sorted list of tuples containing the shift value (i) and φ(i), the percent likihood that 'i'
-///was the shift value used to encipher the text. The smaller φ(i), closer the deciphered text was
-///to English (and is more likely to be the original plaintext).
-/// 
-/// Internally uses the English model proposed in “Views for Multilevel Database Security,” 
-/// IEEE Transactions on Software Engineering 13 (2), pp. 129–140 (Feb. 1987).
-pub fn frequency_analysis<S>(ciphertext: S) -> Vec<(i32,f32)>
+///returning a sorted list of tuples containing the shift value (i) and χ²(i), Pearson's
+///chi-squared goodness-of-fit statistic between the deciphered text's letter counts and
+///the counts expected under `model`. The smaller χ²(i), the closer the deciphered text was
+///to `model`'s language (and is more likely to be the original plaintext).
+pub fn frequency_analysis<S>(ciphertext: S, model: &FreqTable) -> Vec<(i32,f32)>
 where S: AsRef<str> {
-    let mut plaintexts = (0..26).map(|i|(i, phi(letter_frequency(caesar::decrypt(ciphertext.as_ref(), i)))))
+    let mut plaintexts = (0..26).map(|i|(i, chi_squared(&letter_counts(caesar::decrypt(ciphertext.as_ref(), i)), model)))
                                             .collect::<Vec<(i32,f32)>>();
 
     plaintexts.sort_by(|a,b| a.1.partial_cmp(&b.1).unwrap());
@@ -57,38 +22,39 @@ where S: AsRef<str> {
     plaintexts
 }
 
-///Analyzes a string 'ciphertext', returning mapping of each alphabetic ASCII character
-///to the percent of the text that the character makes up (discluding alphabetic ASCII characters).
-fn letter_frequency<S: AsRef<str>>(ciphertext: S) -> HashMap<char,f32> {
-    let mut count: HashMap<char,f32> = HashMap::new();
+///Analyzes a string 'ciphertext', returning a mapping of each alphabetic ASCII character
+///to the number of times it occurs in the text (discluding non-alphabetic ASCII characters).
+fn letter_counts<S: AsRef<str>>(ciphertext: S) -> HashMap<char,u32> {
+    let mut count: HashMap<char,u32> = HashMap::new();
 
     for ch in ciphertext.as_ref().chars() {
         if ch.is_ascii_alphabetic() {
-            count.entry(ch.to_ascii_lowercase()).and_modify(|curr| *curr += 1.0f32).or_insert(1.0f32);
+            count.entry(ch.to_ascii_lowercase()).and_modify(|curr| *curr += 1).or_insert(1);
         }
     }
 
-    let total_letters: f32 = count.values().sum();
-    for frequency in count.values_mut() {
-        *frequency /= total_letters;
-    }
-
     count
 }
 
-///In statistics, φ represents the correlation between two binary variables.
-///Here, we are measuring φ(i), the correlation between our model of the English language
-///and the decrypted ciphertext for each shift value (0..=25). The smaller the difference,
-///the closer the decrypted ciphertext is to English
-fn phi(ciphertext: HashMap<char,f32>) -> f32 {
-    let mut phi = 0f32;
-
-    for (ch, cipher_freq) in ciphertext.iter() {
-        let english_freq = ENGLISH_MODEL.get(ch).expect("ciphertext only contains lowercase alphabetic ASCII");
-        phi += cipher_freq - english_freq;
+///Computes Pearson's chi-squared statistic χ² = Σ (Oᶜ − Eᶜ)² / Eᶜ over the 26 letters,
+///where Oᶜ is the observed count of letter c in `counts` and Eᶜ = freq(c) × N is the count
+///expected under `model`, with N the total number of letters in `counts`. Letters absent
+///from `counts` are treated as Oᶜ = 0. The smaller the result, the closer `counts`
+///matches `model`'s expected letter distribution.
+pub(crate) fn chi_squared(counts: &HashMap<char,u32>, model: &FreqTable) -> f32 {
+    let total: u32 = counts.values().sum();
+    let mut chi_squared = 0f32;
+
+    for ch in ALPHABET {
+        let observed = *counts.get(&ch).unwrap_or(&0) as f32;
+        let expected = model.get(ch) * total as f32;
+
+        if expected != 0.0 {
+            chi_squared += (observed - expected).powi(2) / expected;
+        }
     }
 
-    phi
+    chi_squared
 }
 
 
@@ -154,4 +120,89 @@ where S: AsRef<str> {
     avg_key = avg_key % ALPHABET.len() as f32;
     println!("After mod: {avg_key}");
     Some(avg_key.round() as usize)
+}
+
+
+/*
+    SECTION 3 of 4: AUTOMATIC CIPHERTEXT-ONLY ATTACK
+*/
+
+///Decides whether a candidate plaintext is plausible, so [`auto_decrypt`] can pick a
+///winner from [`frequency_analysis`]'s ranking without a human eyeballing the results.
+pub trait Validator {
+    fn validate(&self, text: &str) -> bool;
+}
+
+///Accepts a candidate plaintext if at least `threshold` of its whitespace-split tokens
+///are found (case-insensitively) in a supplied word list.
+pub struct DictionaryValidator {
+    words: std::collections::HashSet<String>,
+    threshold: f32,
+}
+
+impl DictionaryValidator {
+    pub fn new<I: IntoIterator<Item = S>, S: AsRef<str>>(words: I, threshold: f32) -> Self {
+        DictionaryValidator {
+            words: words.into_iter().map(|w| w.as_ref().to_ascii_lowercase()).collect(),
+            threshold,
+        }
+    }
+}
+
+impl Validator for DictionaryValidator {
+    fn validate(&self, text: &str) -> bool {
+        let tokens = text.split_whitespace().collect::<Vec<&str>>();
+
+        if tokens.is_empty() {
+            return false;
+        }
+
+        let matches = tokens.iter().filter(|token| self.words.contains(&token.to_ascii_lowercase())).count();
+
+        (matches as f32 / tokens.len() as f32) >= self.threshold
+    }
+}
+
+///Accepts a candidate plaintext if at least `threshold` of its characters are
+///printable ASCII (letters, digits, punctuation and symbols) or ASCII whitespace.
+pub struct PrintableRatioValidator {
+    threshold: f32,
+}
+
+impl PrintableRatioValidator {
+    pub fn new(threshold: f32) -> Self {
+        PrintableRatioValidator { threshold }
+    }
+}
+
+impl Validator for PrintableRatioValidator {
+    fn validate(&self, text: &str) -> bool {
+        if text.is_empty() {
+            return false;
+        }
+
+        let printable = text.chars().filter(|ch| ch.is_ascii_graphic() || ch.is_ascii_whitespace()).count();
+
+        (printable as f32 / text.chars().count() as f32) >= self.threshold
+    }
+}
+
+///Performs a ciphertext-only attack and returns the single plaintext a human would pick,
+///instead of [`frequency_analysis`]'s full scored table. Candidates are tried in order of
+///increasing chi-squared score (most English-like first); the first one `validator` accepts
+///is returned along with the shift that produced it and the number of candidates tried.
+///Returns an error if no candidate validates.
+pub fn auto_decrypt<S, V>(ciphertext: S, validator: &V, model: &FreqTable) -> Result<(String, i32, usize), String>
+where S: AsRef<str>, V: Validator {
+    let ranked_shifts = frequency_analysis(ciphertext.as_ref(), model);
+
+    for (attempt, (shift, _)) in ranked_shifts.iter().enumerate() {
+        let candidate = caesar::decrypt(ciphertext.as_ref(), *shift);
+
+        if validator.validate(&candidate) {
+            return Ok((candidate, *shift, attempt + 1));
+        }
+    }
+
+    Err(String::from("no candidate plaintext passed validation"))
 }
\ No newline at end of file
@@ -0,0 +1,89 @@
+use crate::cryptanalysis::freq_table::FreqTable;
+use crate::cryptanalysis::shift::frequency_analysis;
+use crate::cryptography::caesar::ALPHABET;
+use crate::cryptography::vigenere::decrypt;
+
+///The average Index of Coincidence of English prose; random text sits closer to [`RANDOM_IOC`].
+const ENGLISH_IOC: f32 = 0.0667;
+
+///The average Index of Coincidence of random text.
+const RANDOM_IOC: f32 = 0.0385;
+
+///Halfway between [`RANDOM_IOC`] and [`ENGLISH_IOC`]. A candidate key length whose columns'
+///average IoC clears this is considered genuinely English, not just a multiple of the true
+///key length coincidentally scoring close to it.
+const IOC_THRESHOLD: f32 = (RANDOM_IOC + ENGLISH_IOC) / 2.0;
+
+///Recovers an unknown Vigenère key from ciphertext alone and returns the key
+///alongside the decrypted plaintext. `max_key_length` bounds the key lengths
+///tried while estimating the key length (candidates `1..=max_key_length`), and
+///`model` is the language frequency table each column is scored against.
+pub fn recover_key<S: AsRef<str>>(ciphertext: S, max_key_length: usize, model: &FreqTable) -> (String, String) {
+    let ciphertext = ciphertext.as_ref();
+    let key_length = estimate_key_length(ciphertext, max_key_length);
+
+    let columns = split_into_columns(ciphertext, key_length);
+    let key = columns.iter()
+        .map(|column| {
+            let shift = frequency_analysis(column, model)[0].0;
+            ALPHABET[(shift.rem_euclid(26)) as usize]
+        })
+        .collect::<String>();
+
+    let plaintext = decrypt(ciphertext.to_string(), key.clone());
+
+    (key, plaintext)
+}
+
+///Estimates the Vigenère key length by computing the average Index of Coincidence for
+///each candidate length in `1..=max_length` and returning the smallest one whose average
+///IoC clears [`IOC_THRESHOLD`] (a multiple of the true key length also looks English, since
+///each of its columns is itself a mix of full periods of the true key, so the smallest
+///passing length is preferred). Falls back to the length closest to [`ENGLISH_IOC`] overall
+///if none clears the threshold.
+fn estimate_key_length<S: AsRef<str>>(ciphertext: S, max_length: usize) -> usize {
+    let scores = (1..=max_length)
+        .map(|length| {
+            let columns = split_into_columns(ciphertext.as_ref(), length);
+            let avg_ioc = columns.iter().map(|column| index_of_coincidence(column)).sum::<f32>() / columns.len() as f32;
+            (length, avg_ioc)
+        })
+        .collect::<Vec<(usize, f32)>>();
+
+    scores.iter()
+        .find(|(_, avg_ioc)| *avg_ioc >= IOC_THRESHOLD)
+        .or_else(|| scores.iter().min_by(|a, b| (a.1 - ENGLISH_IOC).abs().partial_cmp(&(b.1 - ENGLISH_IOC).abs()).unwrap()))
+        .map(|(length, _)| *length)
+        .expect("max_length must be at least 1")
+}
+
+///Splits `text` into `key_length` columns, where the character at index `i` (counting
+///only alphabetic ASCII characters) goes into column `i % key_length`.
+fn split_into_columns(text: &str, key_length: usize) -> Vec<String> {
+    let mut columns = vec![String::new(); key_length];
+
+    for (i, ch) in text.chars().filter(|ch| ch.is_ascii_alphabetic()).enumerate() {
+        columns[i % key_length].push(ch);
+    }
+
+    columns
+}
+
+///Computes the Index of Coincidence of `text`: Σ nᵢ(nᵢ−1) / (N(N−1)) over the
+///26 letter counts, where N is the number of alphabetic characters in `text`.
+fn index_of_coincidence(text: &str) -> f32 {
+    let mut counts = [0u32; 26];
+    let mut n = 0u32;
+
+    for ch in text.chars().filter(|ch| ch.is_ascii_alphabetic()) {
+        counts[(ch.to_ascii_lowercase() as u8 - b'a') as usize] += 1;
+        n += 1;
+    }
+
+    if n < 2 {
+        return 0.0;
+    }
+
+    let numerator: f32 = counts.iter().map(|&n_i| (n_i * n_i.saturating_sub(1)) as f32).sum();
+    numerator / (n * (n - 1)) as f32
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use crate::cryptanalysis::freq_table::FreqTable;
+use crate::cryptanalysis::shift::chi_squared;
+use crate::cryptography::xor;
+
+///Added to a candidate's chi-squared score for every non-printable byte it decrypts to,
+///so that binary garbage (the result of XOR-ing with the wrong key) always scores worse
+///than genuine, if awkward, English text.
+const NON_PRINTABLE_PENALTY: f32 = 1000.0;
+
+///The minimum fraction of a candidate's bytes that must be ASCII letters for it to be
+///considered English-like at all. Below this, a candidate is all but letter-free (e.g.
+///digits and punctuation from a wrong key) and chi-squared over its empty letter counts
+///would otherwise be 0.0 — a spurious perfect score — so it is penalized instead.
+const MIN_LETTER_RATIO: f32 = 0.5;
+
+///Recovers a single-byte XOR key from `ciphertext` with no prior knowledge, returning the
+///key, the decrypted bytes, and a confidence score (the winning candidate's chi-squared
+///score plus any penalties; the smaller, the more confident). Tries every key `0..=255`, scoring each candidate plaintext
+///against `model`'s letter frequencies the same way [`crate::cryptanalysis::shift`] does.
+pub fn crack_single_byte(ciphertext: &[u8], model: &FreqTable) -> (u8, Vec<u8>, f32) {
+    (0u8..=255)
+        .map(|key| {
+            let plaintext = xor::decrypt(ciphertext, &[key]);
+            let score = score(&plaintext, model);
+            (key, plaintext, score)
+        })
+        .min_by(|a, b| a.2.partial_cmp(&b.2).unwrap())
+        .expect("0..=255 is non-empty")
+}
+
+///Scores `bytes` by how closely it matches `model`'s language: chi-squared over the ASCII
+///letters (counted case-insensitively) plus [`NON_PRINTABLE_PENALTY`] for every byte that
+///isn't printable ASCII or whitespace, plus [`NON_PRINTABLE_PENALTY`] again if fewer than
+///[`MIN_LETTER_RATIO`] of the bytes are letters at all. The smaller the score, the more
+///plausible the plaintext.
+fn score(bytes: &[u8], model: &FreqTable) -> f32 {
+    let mut counts: HashMap<char,u32> = HashMap::new();
+    let mut penalty = 0f32;
+
+    for &byte in bytes {
+        let ch = byte as char;
+
+        if ch.is_ascii_alphabetic() {
+            counts.entry(ch.to_ascii_lowercase()).and_modify(|curr| *curr += 1).or_insert(1);
+        } else if !ch.is_ascii_graphic() && !ch.is_ascii_whitespace() {
+            penalty += NON_PRINTABLE_PENALTY;
+        }
+    }
+
+    let letter_ratio = counts.values().sum::<u32>() as f32 / bytes.len().max(1) as f32;
+    if letter_ratio < MIN_LETTER_RATIO {
+        penalty += NON_PRINTABLE_PENALTY;
+    }
+
+    chi_squared(&counts, model) + penalty
+}
+
+///The number of candidate key sizes (ranked by normalized Hamming distance) carried
+///forward into the full column-by-column crack in [`crack_repeating_key`].
+const KEYSIZE_CANDIDATES: usize = 3;
+
+///Recovers a repeating-key XOR key from `ciphertext` with no prior knowledge, returning
+///the key, the decrypted bytes, and a confidence score (the winning candidate's chi-squared
+///score plus any penalties; the smaller, the more confident). First estimates the key size via normalized Hamming
+///distance, then cracks each candidate size column-by-column as an independent single-byte
+///XOR problem against `model`, and keeps whichever candidate size scores best overall.
+pub fn crack_repeating_key(ciphertext: &[u8], max_key_size: usize, model: &FreqTable) -> (Vec<u8>, Vec<u8>, f32) {
+    candidate_key_sizes(ciphertext, max_key_size)
+        .into_iter()
+        .map(|key_size| {
+            let key = columns(ciphertext, key_size)
+                .iter()
+                .map(|column| crack_single_byte(column, model).0)
+                .collect::<Vec<u8>>();
+
+            let plaintext = xor::decrypt(ciphertext, &key);
+            let candidate_score = score(&plaintext, model);
+
+            (key, plaintext, candidate_score)
+        })
+        .min_by(|a, b| a.2.partial_cmp(&b.2).unwrap())
+        .expect("candidate_key_sizes returns at least one candidate")
+}
+
+///Estimates the most likely key sizes in `2..=max_key_size` by computing the normalized
+///Hamming distance between adjacent blocks of ciphertext for each candidate size (the true
+///key size tends to minimize it, since repeating-key XOR output blocks under the same key
+///diverge less than those under different keys), and returning the [`KEYSIZE_CANDIDATES`]
+///smallest. Averages over every available block for a given size rather than just the
+///first few, since with short ciphertext only a handful of blocks exist per size and a
+///small sample is too noisy to rank the true key size above unrelated sizes.
+fn candidate_key_sizes(ciphertext: &[u8], max_key_size: usize) -> Vec<usize> {
+    let mut sizes = (2..=max_key_size)
+        .filter_map(|key_size| {
+            let blocks = ciphertext.chunks(key_size).collect::<Vec<&[u8]>>();
+
+            if blocks.len() < 2 {
+                return None;
+            }
+
+            let total_distance = blocks.windows(2).map(|pair| hamming(pair[0], pair[1])).sum::<u32>();
+            let pairs = blocks.len() - 1;
+            let normalized = total_distance as f32 / (key_size * pairs) as f32;
+
+            Some((key_size, normalized))
+        })
+        .collect::<Vec<(usize, f32)>>();
+
+    sizes.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+    sizes.truncate(KEYSIZE_CANDIDATES);
+    sizes.into_iter().map(|(key_size, _)| key_size).collect()
+}
+
+///Transposes `ciphertext` into `key_size` columns, where byte `i` goes to column `i % key_size`.
+fn columns(ciphertext: &[u8], key_size: usize) -> Vec<Vec<u8>> {
+    let mut columns = vec![Vec::new(); key_size];
+
+    for (i, &byte) in ciphertext.iter().enumerate() {
+        columns[i % key_size].push(byte);
+    }
+
+    columns
+}
+
+///Computes the Hamming distance between `a` and `b`: the number of differing bits
+///across their bytes. Only the bytes common to both slices' shared length are compared.
+fn hamming(a: &[u8], b: &[u8]) -> u32 {
+    a.iter().zip(b.iter()).map(|(x, y)| (x ^ y).count_ones()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ///Long enough (well over 40x the widest key size tried below) that every candidate
+    ///keysize's transposed columns carry enough bytes for the per-column single-byte
+    ///crack to be statistically reliable.
+    fn english_text() -> String {
+        "the quick brown fox jumps over the lazy dog while the sun sets \
+        slowly behind the hills and the wind carries the scent of rain across the quiet fields \
+        as travelers make their way home before the storm arrives and the village lights begin \
+        to glow against the darkening sky ".repeat(15)
+    }
+
+    #[test]
+    fn cracks_repeating_key_xor_for_self_encrypted_english() {
+        let plaintext = english_text();
+
+        for key in [b"SECRET".as_slice(), b"KEY".as_slice(), b"HIDDENKEY".as_slice()] {
+            let ciphertext = xor::encrypt(plaintext.as_bytes(), key);
+            let (_, recovered, _) = crack_repeating_key(&ciphertext, 40, &FreqTable::english());
+
+            assert_eq!(recovered, plaintext.as_bytes(), "failed to crack key {:?}", String::from_utf8_lossy(key));
+        }
+    }
+
+    #[test]
+    fn cracks_single_byte_xor_for_self_encrypted_english() {
+        let plaintext = english_text();
+        let ciphertext = xor::encrypt(plaintext.as_bytes(), &[0x53]);
+        let (key, recovered, _) = crack_single_byte(&ciphertext, &FreqTable::english());
+
+        assert_eq!(key, 0x53);
+        assert_eq!(recovered, plaintext.as_bytes());
+    }
+}
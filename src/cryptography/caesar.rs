@@ -11,8 +11,10 @@ pub fn decrypt<S: AsRef<str>>(plaintext: S, shift: i32) -> String {
 fn rotn<S: AsRef<str>>(ciphertext: S, shift: i32) -> String {
     let iter = ciphertext.as_ref()
             .chars()
-            .map(|c| if c.is_ascii_alphabetic() {
-                    shift_n(&c.to_ascii_lowercase(), shift)
+            .map(|c| if c.is_ascii_uppercase() {
+                    shift_n(&c.to_ascii_lowercase(), shift).to_ascii_uppercase()
+                } else if c.is_ascii_lowercase() {
+                    shift_n(&c, shift)
                 } else {
                     c
                 }
@@ -24,14 +26,38 @@ fn rotn<S: AsRef<str>>(ciphertext: S, shift: i32) -> String {
 
 fn shift_n(c: &char, rot: i32) -> char {
     let idx = ALPHABET.iter().position(|&ch| *c == ch).expect("casted to lowercase") as i32;
-    let mut shifted_idx = idx + rot;
-    
-    //mod wasn't working for some reason, so basic addition it is.
-    if shifted_idx < 0 {
-        shifted_idx += 26;
-    } else if shifted_idx > 26 {
-        shifted_idx -= 26;
-    }
+    let shifted_idx = ((idx + rot) % 26 + 26) % 26;
 
     ALPHABET[shifted_idx as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_preserves_mixed_case_and_punctuation() {
+        let plaintext = "Hello, World! It's a Caesar cipher.";
+
+        assert_eq!(decrypt(encrypt(plaintext, 7), 7), plaintext);
+    }
+
+    #[test]
+    fn round_trip_at_zero_and_twenty_six_boundary() {
+        let plaintext = "Hello, World!";
+
+        assert_eq!(encrypt(&plaintext, 0), plaintext);
+        assert_eq!(encrypt(&plaintext, 26), plaintext);
+        assert_eq!(decrypt(encrypt(&plaintext, 26), 26), plaintext);
+    }
+
+    #[test]
+    fn shift_preserves_case_of_each_letter() {
+        assert_eq!(encrypt("AbCdZz", 1), "BcDeAa");
+    }
+
+    #[test]
+    fn shift_leaves_non_alphabetic_characters_untouched() {
+        assert_eq!(encrypt("123 !@# \n", 5), "123 !@# \n");
+    }
 }
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub mod caesar;
+pub mod vigenere;
+pub mod xor;
@@ -0,0 +1,43 @@
+use crate::cryptography::caesar::ALPHABET;
+
+pub fn encrypt<S: AsRef<str>>(plaintext: S, key: S) -> String {
+    vigenere(plaintext, key, 1)
+}
+
+pub fn decrypt<S: AsRef<str>>(ciphertext: S, key: S) -> String {
+    vigenere(ciphertext, key, -1)
+}
+
+///Applies a Vigenère shift to `text` using `key`, repeating the key over the
+///alphabetic characters of the text. `direction` should be `1` to encrypt and
+///`-1` to decrypt.
+fn vigenere<S: AsRef<str>>(text: S, key: S, direction: i32) -> String {
+    let key_shifts = key.as_ref()
+        .chars()
+        .filter(|ch| ch.is_ascii_alphabetic())
+        .map(|ch| ALPHABET.iter().position(|&a| a == ch.to_ascii_lowercase()).expect("filtered to alphabetic") as i32)
+        .collect::<Vec<i32>>();
+
+    assert!(!key_shifts.is_empty(), "key must contain at least one alphabetic character");
+
+    let mut key_idx = 0;
+    let iter = text.as_ref()
+        .chars()
+        .map(|c| if c.is_ascii_alphabetic() {
+                let shift = direction * key_shifts[key_idx % key_shifts.len()];
+                key_idx += 1;
+                shift_n(&c.to_ascii_lowercase(), shift)
+            } else {
+                c
+            }
+        );
+
+    String::from_iter(iter)
+}
+
+fn shift_n(c: &char, rot: i32) -> char {
+    let idx = ALPHABET.iter().position(|&ch| *c == ch).expect("casted to lowercase") as i32;
+    let shifted_idx = ((idx + rot) % 26 + 26) % 26;
+
+    ALPHABET[shifted_idx as usize]
+}
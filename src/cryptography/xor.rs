@@ -0,0 +1,18 @@
+pub fn encrypt(plaintext: &[u8], key: &[u8]) -> Vec<u8> {
+    xor(plaintext, key)
+}
+
+pub fn decrypt(ciphertext: &[u8], key: &[u8]) -> Vec<u8> {
+    xor(ciphertext, key)
+}
+
+///XORs `data` against `key`, repeating `key` as many times as necessary. XOR is its
+///own inverse, so this single routine serves both `encrypt` and `decrypt`.
+fn xor(data: &[u8], key: &[u8]) -> Vec<u8> {
+    assert!(!key.is_empty(), "key must contain at least one byte");
+
+    data.iter()
+        .enumerate()
+        .map(|(i, byte)| byte ^ key[i % key.len()])
+        .collect()
+}